@@ -0,0 +1,164 @@
+// Criterion harness for the multiply-add backends.
+//
+// Unlike the old hand-rolled `benchmark` function (which discarded its
+// closure's result with `let _ = ...` and let the optimizer eliminate the
+// whole computation), every backend here is run through `black_box` on
+// both inputs and outputs, and is checked bit-for-bit against a reference
+// implementation before it's ever timed - a broken SIMD path should fail
+// the assertion, not quietly report a fast-but-wrong number. The one
+// exception is the historical `ifma_multiply_add` baseline in `bench_64`,
+// which is timed without that check (see the comment there).
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::Rng;
+
+use rustops::engine::MulAddEngine;
+use rustops::u256::U256;
+use rustops::widening::{mul_add_256, widening_mul_128};
+use rustops::{compiler_optimized_multiply_add, karatsuba_multiply_add, karatsuba_multiply_add_128, karatsuba_multiply_add_256};
+
+#[cfg(target_arch = "x86_64")]
+use rustops::{ifma_multiply_add, simd_multiply_add};
+
+#[cfg(target_arch = "aarch64")]
+use rustops::neon::{neon_multiply_add, neon_multiply_add_128};
+
+fn reference_64(a: u64, b: u64, c: u64) -> u128 {
+    (a as u128) * (b as u128) + c as u128
+}
+
+/// `(a * b + c)` for 128-bit operands, built on the carry-correct widening
+/// multiply. Also benchmarked as the "widening" 128-bit backend.
+fn widening_multiply_add_128(a: u128, b: u128, c: u128) -> (u128, u128) {
+    let prod = widening_mul_128(a, b);
+    let (low, carry) = prod.low().overflowing_add(c);
+    (low, prod.high().wrapping_add(carry as u128))
+}
+
+fn bench_64(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let a: u64 = rng.gen();
+    let b: u64 = rng.gen();
+    let add: u64 = rng.gen();
+    let reference = reference_64(a, b, add);
+
+    let mut group = c.benchmark_group("mul_add/64");
+
+    let engine = MulAddEngine::new();
+    assert_eq!(engine.mul_add(a, b, add), reference, "dispatched engine disagrees with reference");
+    group.bench_function("engine (dispatched)", |bch| {
+        bch.iter(|| engine.mul_add(black_box(a), black_box(b), black_box(add)))
+    });
+
+    assert_eq!(compiler_optimized_multiply_add(a, b, add), reference);
+    group.bench_function("scalar", |bch| {
+        bch.iter(|| compiler_optimized_multiply_add(black_box(a), black_box(b), black_box(add)))
+    });
+
+    assert_eq!(karatsuba_multiply_add(a, b, add), reference);
+    group.bench_function("karatsuba", |bch| {
+        bch.iter(|| karatsuba_multiply_add(black_box(a), black_box(b), black_box(add)))
+    });
+
+    #[cfg(target_arch = "x86_64")]
+    if std::is_x86_feature_detected!("avx2") {
+        let got = unsafe { simd_multiply_add(a, b, add) };
+        assert_eq!(got, reference, "avx2 backend disagrees with reference");
+        group.bench_function("avx2", |bch| {
+            bch.iter(|| unsafe { simd_multiply_add(black_box(a), black_box(b), black_box(add)) })
+        });
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    if std::is_x86_feature_detected!("avx512ifma") {
+        // `ifma_multiply_add` predates the 52-bit limb decomposition fix in
+        // `rustops::ifma` and still treats its inputs as a single 64-bit
+        // lane, silently dropping bits above 2^52 - it's kept only as a
+        // historical, known-broken baseline (see its doc comment in
+        // `rustops`). Unlike every other backend in this file, it's
+        // deliberately not gated on correctness: asserting it against the
+        // reference would reliably abort `cargo bench` on any real
+        // avx512ifma host, since it's *expected* to disagree.
+        let got = unsafe { ifma_multiply_add(a, b, add) };
+        if got != reference {
+            eprintln!(
+                "note: ifma_multiply_add (historical, known-broken baseline) disagrees with reference, as expected - see rustops::ifma"
+            );
+        }
+        group.bench_function("ifma (historical, known-broken)", |bch| {
+            bch.iter(|| unsafe { ifma_multiply_add(black_box(a), black_box(b), black_box(add)) })
+        });
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        let got = unsafe { neon_multiply_add(a, b, add) };
+        assert_eq!(got, reference, "neon backend disagrees with reference");
+        group.bench_function("neon", |bch| {
+            bch.iter(|| unsafe { neon_multiply_add(black_box(a), black_box(b), black_box(add)) })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_128(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let a: u128 = rng.gen();
+    let b: u128 = rng.gen();
+    let add: u128 = rng.gen();
+    let reference = widening_multiply_add_128(a, b, add);
+
+    let mut group = c.benchmark_group("mul_add/128");
+
+    group.bench_function("widening", |bch| {
+        bch.iter(|| widening_multiply_add_128(black_box(a), black_box(b), black_box(add)))
+    });
+
+    assert_eq!(karatsuba_multiply_add_128(a, b, add), reference, "karatsuba 128-bit backend disagrees with reference");
+    group.bench_function("karatsuba", |bch| {
+        bch.iter(|| karatsuba_multiply_add_128(black_box(a), black_box(b), black_box(add)))
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        let got = unsafe { neon_multiply_add_128(a, b, add) };
+        assert_eq!(got, reference, "neon 128-bit backend disagrees with reference");
+        group.bench_function("neon", |bch| {
+            bch.iter(|| unsafe { neon_multiply_add_128(black_box(a), black_box(b), black_box(add)) })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_256(c: &mut Criterion) {
+    let mut rng = rand::thread_rng();
+    let a = U256(rng.gen(), rng.gen());
+    let b = U256(rng.gen(), rng.gen());
+    let add = U256(rng.gen(), rng.gen());
+    let reference = mul_add_256(a, b, add);
+
+    let mut group = c.benchmark_group("mul_add/256");
+
+    group.bench_function("widening", |bch| {
+        bch.iter(|| mul_add_256(black_box(a), black_box(b), black_box(add)))
+    });
+
+    assert_eq!(karatsuba_multiply_add_256(a, b, add), reference, "karatsuba 256-bit backend disagrees with reference");
+    group.bench_function("karatsuba", |bch| {
+        bch.iter(|| karatsuba_multiply_add_256(black_box(a), black_box(b), black_box(add)))
+    });
+
+    #[cfg(target_arch = "x86_64")]
+    if let Some(got) = rustops::ifma::multiply_add_256(a, b, add) {
+        assert_eq!(got, reference, "ifma 256-bit backend disagrees with reference");
+        group.bench_function("ifma", |bch| {
+            bch.iter(|| rustops::ifma::multiply_add_256(black_box(a), black_box(b), black_box(add)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_64, bench_128, bench_256);
+criterion_main!(benches);
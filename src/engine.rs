@@ -0,0 +1,84 @@
+// Runtime-dispatched multiply-add engine.
+//
+// Mirrors the pattern crc32fast uses for its PCLMULQDQ `State`: probe the
+// CPU once at construction time, cache the fastest available backend as a
+// function pointer, and expose a single safe call site. Callers pay the
+// `is_x86_feature_detected!` cost once instead of on every call, and never
+// have to touch `unsafe` themselves.
+use crate::compiler_optimized_multiply_add;
+
+#[cfg(target_arch = "x86_64")]
+use crate::ifma;
+
+#[cfg(target_arch = "x86_64")]
+use crate::simd_multiply_add;
+
+#[cfg(target_arch = "aarch64")]
+use crate::neon::neon_multiply_add;
+
+type MulAddFn = fn(u64, u64, u64) -> u128;
+
+/// Selects the fastest available 64-bit multiply-add backend for the
+/// current CPU and dispatches to it through a cached function pointer.
+///
+/// Construction probes the CPU once; every subsequent `mul_add` call is a
+/// plain function-pointer indirection with no feature re-detection and no
+/// `unsafe` at the call site.
+pub struct MulAddEngine {
+    f: MulAddFn,
+}
+
+impl MulAddEngine {
+    /// Probes the CPU for the fastest available backend: IFMA then AVX2 on
+    /// x86_64, NEON on aarch64, falling back to the portable
+    /// compiler-optimized implementation everywhere else.
+    pub fn new() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if std::is_x86_feature_detected!("avx512ifma") {
+                return Self { f: |a, b, c| unsafe { ifma::multiply_add_64_ifma(a, b, c) } };
+            }
+            if std::is_x86_feature_detected!("avx2") {
+                return Self { f: |a, b, c| unsafe { simd_multiply_add(a, b, c) } };
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            return Self { f: |a, b, c| unsafe { neon_multiply_add(a, b, c) } };
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        Self { f: compiler_optimized_multiply_add }
+    }
+
+    /// Computes `a * b + c` using the backend chosen at construction time.
+    #[inline]
+    pub fn mul_add(&self, a: u64, b: u64, c: u64) -> u128 {
+        (self.f)(a, b, c)
+    }
+}
+
+impl Default for MulAddEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_add_matches_u128_reference_random() {
+        let engine = MulAddEngine::new();
+        for _ in 0..10_000 {
+            let a: u64 = rand::random();
+            let b: u64 = rand::random();
+            let c: u64 = rand::random();
+
+            let want = (a as u128) * (b as u128) + c as u128;
+            assert_eq!(engine.mul_add(a, b, c), want);
+        }
+    }
+}
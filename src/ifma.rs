@@ -0,0 +1,273 @@
+// AVX-512 IFMA big-integer multiply-add.
+//
+// `vpmadd52luq`/`vpmadd52huq` operate on packed 64-bit lanes but only ever
+// read the low 52 bits of each source lane: `vpmadd52luq` accumulates
+// `lo52(a*b)` into the destination lane and `vpmadd52huq` accumulates
+// `hi52(a*b)`. That only gives a correct multiply if the inputs are first
+// decomposed into 52-bit limbs (a "redundant" base-2^52 representation) -
+// treating a 64-bit lane as a single limb, as the crate's original
+// `ifma_multiply_add` did, silently drops the top 12 bits of every source.
+// This module does the decomposition, issues one accumulate per limb pair,
+// and then normalizes the redundant columns back into packed 64-bit words -
+// for both the 256-bit big-integer case ([`multiply_add_256`]) and the
+// single 64-bit operand pair ([`multiply_add_64`], whose unchecked core
+// `MulAddEngine` dispatches to).
+use crate::u256::U256;
+
+const LIMB_BITS: usize = 52;
+const LIMB_MASK: u64 = (1u64 << LIMB_BITS) - 1;
+const LIMBS: usize = 5; // 5 * 52 = 260 bits, enough to cover a 256-bit operand
+
+/// Splits a 256-bit value into five 52-bit limbs, least-significant first.
+fn to_limbs(x: U256) -> [u64; LIMBS] {
+    let words = x.to_words();
+    let mut limbs = [0u64; LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let bit_offset = i * LIMB_BITS;
+        let word_idx = bit_offset / 64;
+        let bit_in_word = bit_offset % 64;
+        let lo = *words.get(word_idx).unwrap_or(&0) as u128;
+        let hi = *words.get(word_idx + 1).unwrap_or(&0) as u128;
+        let window = lo | (hi << 64);
+        *limb = ((window >> bit_in_word) as u64) & LIMB_MASK;
+    }
+    limbs
+}
+
+/// Adds `value << bit_shift` into a little-endian word array, propagating
+/// carries the way `__udivmodti4`-style limb arithmetic does.
+fn add_shifted(words: &mut [u64], value: u64, bit_shift: usize) {
+    let word_idx = bit_shift / 64;
+    if word_idx >= words.len() {
+        return;
+    }
+    let bit_in_word = bit_shift % 64;
+    let widened = (value as u128) << bit_in_word;
+    let lo = widened as u64;
+    let hi = (widened >> 64) as u64;
+
+    let (sum, carry) = words[word_idx].overflowing_add(lo);
+    words[word_idx] = sum;
+    if carry {
+        propagate_carry(words, word_idx + 1);
+    }
+
+    if hi != 0 && word_idx + 1 < words.len() {
+        let (sum, carry) = words[word_idx + 1].overflowing_add(hi);
+        words[word_idx + 1] = sum;
+        if carry {
+            propagate_carry(words, word_idx + 2);
+        }
+    }
+}
+
+fn propagate_carry(words: &mut [u64], mut idx: usize) {
+    while idx < words.len() {
+        let (sum, carry) = words[idx].overflowing_add(1);
+        words[idx] = sum;
+        if !carry {
+            return;
+        }
+        idx += 1;
+    }
+}
+
+/// Normalizes the redundant base-2^52 column representation (each column
+/// may hold up to `2 * LIMBS - 1` partial sums and so can exceed 52 bits) into
+/// packed 64-bit words, truncated to 256 bits.
+fn normalize(columns: &[u64; 2 * LIMBS]) -> [u64; 4] {
+    let mut words = [0u64; 2 * LIMBS]; // room for the full redundant range before truncating
+    for (k, &col) in columns.iter().enumerate() {
+        add_shifted(&mut words, col, LIMB_BITS * k);
+    }
+    [words[0], words[1], words[2], words[3]]
+}
+
+/// Computes `(a * b + c) mod 2^256` using `vpmadd52luq`/`vpmadd52huq` over
+/// 52-bit limbs packed across `zmm` lanes.
+///
+/// # Safety
+/// Caller must ensure `avx512ifma` is available on the current CPU (the
+/// public [`multiply_add_256`] wrapper does this via
+/// `is_x86_feature_detected!` before calling in).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512ifma")]
+unsafe fn multiply_add_256_ifma(a: U256, b: U256, c: U256) -> U256 {
+    use std::arch::x86_64::*;
+
+    let a_limbs = to_limbs(a);
+    let b_limbs = to_limbs(b);
+
+    let mut a_lanes = [0i64; 8];
+    for i in 0..LIMBS {
+        a_lanes[i] = a_limbs[i] as i64;
+    }
+    let a_vec = _mm512_loadu_epi64(a_lanes.as_ptr());
+    let zero = _mm512_setzero_si512();
+
+    // columns[k] accumulates the (redundant, not yet carry-normalized)
+    // contribution to bits [52*k, 52*(k+1)) of the product.
+    let mut columns = [0u64; 2 * LIMBS];
+    for (j, &b_limb) in b_limbs.iter().enumerate() {
+        let b_vec = _mm512_set1_epi64(b_limb as i64);
+        let lo = _mm512_madd52lo_epu64(zero, a_vec, b_vec);
+        let hi = _mm512_madd52hi_epu64(zero, a_vec, b_vec);
+
+        let mut lo_lanes = [0i64; 8];
+        let mut hi_lanes = [0i64; 8];
+        _mm512_storeu_epi64(lo_lanes.as_mut_ptr(), lo);
+        _mm512_storeu_epi64(hi_lanes.as_mut_ptr(), hi);
+
+        for i in 0..LIMBS {
+            columns[i + j] = columns[i + j].wrapping_add(lo_lanes[i] as u64);
+            columns[i + j + 1] = columns[i + j + 1].wrapping_add(hi_lanes[i] as u64);
+        }
+    }
+
+    let mut words = normalize(&columns);
+    let c_words = c.to_words();
+    let mut carry = false;
+    for i in 0..4 {
+        let (sum, c1) = words[i].overflowing_add(c_words[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        words[i] = sum;
+        carry = c1 || c2;
+    }
+
+    U256::from_words(words)
+}
+
+/// Computes `(a * b + c) mod 2^256` on the IFMA backend if the CPU supports
+/// `avx512ifma`, or returns `None` otherwise.
+pub fn multiply_add_256(a: U256, b: U256, c: U256) -> Option<U256> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx512ifma") {
+            return Some(unsafe { multiply_add_256_ifma(a, b, c) });
+        }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let _ = (a, b, c);
+    }
+    None
+}
+
+const LIMBS_64: usize = 2; // 2 * 52 = 104 bits, enough to cover a 64-bit operand
+
+/// Splits a 64-bit value into two 52-bit limbs, least-significant first.
+fn to_limbs_64(x: u64) -> [u64; LIMBS_64] {
+    [x & LIMB_MASK, x >> LIMB_BITS]
+}
+
+/// Computes `a * b + c` using `vpmadd52luq`/`vpmadd52huq` over 52-bit limbs,
+/// the same decomposition [`multiply_add_256_ifma`] uses, scaled down to a
+/// single 64-bit operand pair.
+///
+/// # Safety
+/// Caller must ensure `avx512ifma` is available on the current CPU (the
+/// public [`multiply_add_64`] wrapper and `MulAddEngine::new` do this via
+/// `is_x86_feature_detected!` before calling in).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512ifma")]
+pub(crate) unsafe fn multiply_add_64_ifma(a: u64, b: u64, c: u64) -> u128 {
+    use std::arch::x86_64::*;
+
+    let a_limbs = to_limbs_64(a);
+    let b_limbs = to_limbs_64(b);
+
+    let mut a_lanes = [0i64; 8];
+    for i in 0..LIMBS_64 {
+        a_lanes[i] = a_limbs[i] as i64;
+    }
+    let a_vec = _mm512_loadu_epi64(a_lanes.as_ptr());
+    let zero = _mm512_setzero_si512();
+
+    let mut columns = [0u64; 2 * LIMBS_64];
+    for (j, &b_limb) in b_limbs.iter().enumerate() {
+        let b_vec = _mm512_set1_epi64(b_limb as i64);
+        let lo = _mm512_madd52lo_epu64(zero, a_vec, b_vec);
+        let hi = _mm512_madd52hi_epu64(zero, a_vec, b_vec);
+
+        let mut lo_lanes = [0i64; 8];
+        let mut hi_lanes = [0i64; 8];
+        _mm512_storeu_epi64(lo_lanes.as_mut_ptr(), lo);
+        _mm512_storeu_epi64(hi_lanes.as_mut_ptr(), hi);
+
+        for i in 0..LIMBS_64 {
+            columns[i + j] = columns[i + j].wrapping_add(lo_lanes[i] as u64);
+            columns[i + j + 1] = columns[i + j + 1].wrapping_add(hi_lanes[i] as u64);
+        }
+    }
+
+    let mut words = [0u64; 2 * LIMBS_64];
+    for (k, &col) in columns.iter().enumerate() {
+        add_shifted(&mut words, col, LIMB_BITS * k);
+    }
+
+    let product = (words[0] as u128) | ((words[1] as u128) << 64);
+    product.wrapping_add(c as u128)
+}
+
+/// Computes `a * b + c` on the IFMA backend if the CPU supports
+/// `avx512ifma`, or returns `None` otherwise.
+///
+/// This is the correctly limb-decomposed replacement for the crate's
+/// original single-lane `ifma_multiply_add`, which treated each 64-bit
+/// input as one opaque lane and silently dropped the top 12 bits of any
+/// operand wider than 52 bits.
+pub fn multiply_add_64(a: u64, b: u64, c: u64) -> Option<u128> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx512ifma") {
+            return Some(unsafe { multiply_add_64_ifma(a, b, c) });
+        }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let _ = (a, b, c);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widening::mul_add_256;
+
+    #[test]
+    fn matches_widening_multiply_when_supported() {
+        if !std::is_x86_feature_detected!("avx512ifma") {
+            eprintln!("skipping: avx512ifma not available on this CPU");
+            return;
+        }
+
+        for _ in 0..1_000 {
+            let a = U256(rand::random(), rand::random());
+            let b = U256(rand::random(), rand::random());
+            let c = U256(rand::random(), rand::random());
+
+            let want = mul_add_256(a, b, c);
+            let got = multiply_add_256(a, b, c).expect("feature was just checked");
+            assert_eq!(got, want);
+        }
+    }
+
+    #[test]
+    fn multiply_add_64_matches_u128_reference_when_supported() {
+        if !std::is_x86_feature_detected!("avx512ifma") {
+            eprintln!("skipping: avx512ifma not available on this CPU");
+            return;
+        }
+
+        for _ in 0..10_000 {
+            let a: u64 = rand::random();
+            let b: u64 = rand::random();
+            let c: u64 = rand::random();
+
+            let want = (a as u128) * (b as u128) + c as u128;
+            let got = multiply_add_64(a, b, c).expect("feature was just checked");
+            assert_eq!(got, want);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+// Multiply-add backends: scalar, Karatsuba, SIMD (AVX2 / AVX-512 IFMA /
+// NEON), and the carry-correct widening-multiply subsystem used as the
+// reference implementation everything else is checked against.
+pub mod engine;
+pub mod ifma;
+#[cfg(target_arch = "aarch64")]
+pub mod neon;
+pub mod u256;
+pub mod widening;
+
+// Original IFMA implementation
+// 1. Inline Assembly Optimization (IFMA) - x86_64 only
+//
+// Note: this treats each 64-bit input as a single lane and so silently
+// drops the top 12 bits of any operand with bits above 2^52 -
+// `vpmadd52luq`/`vpmadd52huq` only read the low 52 bits of each source.
+// `MulAddEngine` no longer dispatches to it; `ifma::multiply_add_64` and
+// `ifma::multiply_add_256` are the correctly limb-decomposed replacements,
+// for a single 64-bit operand pair and multi-word operands respectively.
+// This is kept only as the historical, known-broken baseline the bench
+// harness measures against.
+/// Computes `a * b + c` using a single-lane `vpmadd52luq`/`vpmadd52huq` sequence.
+///
+/// # Safety
+/// Caller must ensure `avx512ifma` is available on the current CPU.
+#[cfg(target_arch = "x86_64")]
+pub unsafe fn ifma_multiply_add(a: u64, b: u64, c: u64) -> u128 {
+    // This function demonstrates direct use of IFMA instructions
+    // It requires unsafe code due to direct hardware interaction
+    let mut result_low: u64;
+    let mut result_high: u64;
+
+    std::arch::asm!(
+        "vpmadd52luq {0}, {2}, {3}",
+        "vpmadd52huq {1}, {2}, {3}",
+        inout(xmm_reg) c => result_low,
+        out(xmm_reg) result_high,
+        in(xmm_reg) a,
+        in(xmm_reg) b,
+    );
+
+    ((result_high as u128) << 64) | (result_low as u128)
+}
+
+// 2. Compiler Optimization (64-bit)
+#[inline(always)]
+pub fn compiler_optimized_multiply_add(a: u64, b: u64, c: u64) -> u128 {
+    // This function relies on the Rust compiler's ability to optimize
+    (a as u128 * b as u128) + c as u128
+}
+
+// 3. High-Level Abstraction (SIMD) - Only for 64-bit
+//
+// `_mm256_mul_epu32` multiplies the low 32 bits of each packed 64-bit lane,
+// so loading `a`/`b` split into 32-bit halves across the four lanes - paired
+// as [a_lo*b_lo, a_lo*b_hi, a_hi*b_lo, a_hi*b_hi] - computes all four
+// schoolbook partial products in a single instruction. An earlier version
+// of this function only computed the diagonal (`a_lo*b_lo`/`a_hi*b_hi`) and
+// silently dropped both cross terms.
+/// Computes `a * b + c` using AVX2.
+///
+/// # Safety
+/// Caller must ensure `avx2` is available on the current CPU.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn simd_multiply_add(a: u64, b: u64, c: u64) -> u128 {
+    use std::arch::x86_64::*;
+    const MASK32: u64 = (1 << 32) - 1;
+    let a_lo = (a & MASK32) as i64;
+    let a_hi = (a >> 32) as i64;
+    let b_lo = (b & MASK32) as i64;
+    let b_hi = (b >> 32) as i64;
+
+    let a_vec = _mm256_set_epi64x(a_hi, a_hi, a_lo, a_lo);
+    let b_vec = _mm256_set_epi64x(b_hi, b_lo, b_hi, b_lo);
+    let products = _mm256_mul_epu32(a_vec, b_vec);
+
+    let mut lanes = [0u64; 4];
+    _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, products);
+    let [p00, p01, p10, p11] = lanes;
+
+    (p00 as u128)
+        .wrapping_add((p01 as u128) << 32)
+        .wrapping_add((p10 as u128) << 32)
+        .wrapping_add((p11 as u128) << 64)
+        .wrapping_add(c as u128)
+}
+
+// 4. Algorithmic Optimization (Karatsuba algorithm) - 64-bit
+//
+// Combines the three partial products by addition, not bitwise OR: `z1`
+// (shifted left 32) and `z2` (shifted left 64) overlap in their middle
+// bits whenever there's a carry, and an earlier version of this function
+// used `|` to combine them, silently discarding that carry.
+pub fn karatsuba_multiply_add(x: u64, y: u64, z: u64) -> u128 {
+    const MASK32: u64 = (1 << 32) - 1;
+    let x0 = x & MASK32;
+    let x1 = x >> 32;
+    let y0 = y & MASK32;
+    let y1 = y >> 32;
+    let z0 = x0 as u128 * y0 as u128;
+    let z2 = x1 as u128 * y1 as u128;
+    let z1 = ((x0 + x1) as u128 * (y0 + y1) as u128) - z0 - z2;
+    z0.wrapping_add(z1 << 32).wrapping_add(z2 << 64).wrapping_add(z as u128)
+}
+
+// 128-bit version
+//
+// `x0 + x1` and `y0 + y1` can each be as large as 2^65 - 2 (every bit of a
+// 64-bit limb set), so their product can exceed 2^128 - multiplying them as
+// plain `u128`s, as an earlier version of this function did, silently
+// wrapped and dropped that carry. `widening::widening_mul_128` widens the
+// product into a `U256` instead, and the rest of the combination is done
+// with `U256`'s carry-correct `Add`/`Sub`/`Shl` so no step can lose a bit.
+pub fn karatsuba_multiply_add_128(x: u128, y: u128, z: u128) -> (u128, u128) {
+    use u256::U256;
+    use widening::widening_mul_128;
+
+    const MASK64: u128 = (1u128 << 64) - 1;
+    let x0 = x & MASK64;
+    let x1 = x >> 64;
+    let y0 = y & MASK64;
+    let y1 = y >> 64;
+
+    let z0 = x0 * y0;
+    let z2 = x1 * y1;
+    let mid = widening_mul_128(x0 + x1, y0 + y1) - U256::from(z0) - U256::from(z2);
+
+    let result = U256::from(z0) + (mid << 64) + (U256::from(z2) << 128) + U256::from(z);
+    (result.low(), result.high())
+}
+
+// 256-bit version
+//
+// `x1 * y1` lands entirely at bit 256 and above, so it's truncated away by
+// the mod-2^256 result; only the cross terms `x0*y1`/`x1*y0` (each shifted
+// by 128 bits) contribute to the high limb, and only their low 128 bits
+// survive the truncation - same reasoning as `widening::mul_add_256`. An
+// earlier version of this function tried to fold that cross term into a
+// third `karatsuba_multiply_add_128` call over `x0.wrapping_add(x1)` /
+// `y0.wrapping_add(y1)`, which silently truncated the carry out of each
+// 128-bit sum and produced a wrong result.
+pub fn karatsuba_multiply_add_256(x: u256::U256, y: u256::U256, z: u256::U256) -> u256::U256 {
+    use u256::U256;
+
+    let (x0, x1) = (x.0, x.1);
+    let (y0, y1) = (y.0, y.1);
+
+    let (low, z0_high) = karatsuba_multiply_add_128(x0, y0, z.0);
+    let cross = x0.wrapping_mul(y1).wrapping_add(x1.wrapping_mul(y0));
+    let high = z0_high.wrapping_add(cross).wrapping_add(z.1);
+
+    U256(low, high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use u256::U256;
+    use widening::{mul_add_256, widening_mul_128};
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn simd_multiply_add_matches_u128_reference_when_supported() {
+        if !std::is_x86_feature_detected!("avx2") {
+            eprintln!("skipping: avx2 not available on this CPU");
+            return;
+        }
+
+        for _ in 0..10_000 {
+            let a: u64 = rand::random();
+            let b: u64 = rand::random();
+            let c: u64 = rand::random();
+
+            let want = (a as u128) * (b as u128) + c as u128;
+            let got = unsafe { simd_multiply_add(a, b, c) };
+            assert_eq!(got, want);
+        }
+    }
+
+    #[test]
+    fn karatsuba_64_matches_u128_reference_random() {
+        for _ in 0..10_000 {
+            let x: u64 = rand::random();
+            let y: u64 = rand::random();
+            let z: u64 = rand::random();
+
+            let want = (x as u128) * (y as u128) + z as u128;
+            assert_eq!(karatsuba_multiply_add(x, y, z), want);
+        }
+    }
+
+    #[test]
+    fn karatsuba_128_matches_widening_random() {
+        for _ in 0..10_000 {
+            let x: u128 = rand::random();
+            let y: u128 = rand::random();
+            let z: u128 = rand::random();
+
+            let want = widening_mul_128(x, y) + U256::from(z);
+            let got = karatsuba_multiply_add_128(x, y, z);
+            assert_eq!(got, (want.low(), want.high()));
+        }
+    }
+
+    #[test]
+    fn karatsuba_256_matches_widening_random() {
+        for _ in 0..10_000 {
+            let x = U256(rand::random(), rand::random());
+            let y = U256(rand::random(), rand::random());
+            let z = U256(rand::random(), rand::random());
+
+            let want = mul_add_256(x, y, z);
+            let got = karatsuba_multiply_add_256(x, y, z);
+            assert_eq!(got, want);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// ARM64 NEON multiply-add backend.
+//
+// x86_64 gets IFMA and AVX2 acceleration; this module gives aarch64 a NEON
+// path in place of the scalar fallback. This mirrors libcrux's structure for its SIMD
+// backends: a parallel `arch::aarch64` module, selected at build time via
+// `target_arch`/`target_feature` and wired into the runtime dispatcher
+// alongside the x86_64 backends.
+//
+// The trick that makes one `vmull_u32` pull double duty: feeding it
+// `[a0, a1]` against `[b0, b1]` yields `[a0*b0, a1*b1]` (the diagonal
+// partial products) in one instruction, and against the lane-swapped
+// `[b1, b0]` yields `[a0*b1, a1*b0]` (the cross terms) in another. The
+// 32x32->64 partial products land two-at-a-time across lanes instead of
+// one scalar multiply at a time.
+#[cfg(target_arch = "aarch64")]
+use std::arch::aarch64::*;
+
+/// Computes `a * b + c` for 64-bit operands using NEON.
+///
+/// # Safety
+/// Caller must ensure the `neon` target feature is available (it is
+/// mandatory on all AArch64 CPUs per the base architecture, but the
+/// intrinsics are still marked `unsafe` upstream).
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+pub unsafe fn neon_multiply_add(a: u64, b: u64, c: u64) -> u128 {
+    let a0 = a as u32;
+    let a1 = (a >> 32) as u32;
+    let b0 = b as u32;
+    let b1 = (b >> 32) as u32;
+
+    let a_vec = vcreate_u32(((a1 as u64) << 32) | a0 as u64); // lanes [a0, a1]
+    let b_straight = vcreate_u32(((b1 as u64) << 32) | b0 as u64); // lanes [b0, b1]
+    let b_swapped = vcreate_u32(((b0 as u64) << 32) | b1 as u64); // lanes [b1, b0]
+
+    let diag = vmull_u32(a_vec, b_straight); // [a0*b0, a1*b1]
+    let cross = vmull_u32(a_vec, b_swapped); // [a0*b1, a1*b0]
+
+    let p00 = vgetq_lane_u64(diag, 0);
+    let p11 = vgetq_lane_u64(diag, 1);
+    let p01 = vgetq_lane_u64(cross, 0);
+    let p10 = vgetq_lane_u64(cross, 1);
+
+    // Same carry-correct assembly as `widening::widening_mul_128`, just
+    // sourcing the partial products from NEON lanes instead of plain u64
+    // multiplication.
+    let (mid, mid_carry) = p01.overflowing_add(p10);
+    let (low, low_carry) = p00.overflowing_add(mid << 32);
+    let high = p11
+        .wrapping_add(mid >> 32)
+        .wrapping_add((mid_carry as u64) << 32)
+        .wrapping_add(low_carry as u64);
+
+    let (low, c_carry) = low.overflowing_add(c);
+    let high = high.wrapping_add(c_carry as u64);
+
+    ((high as u128) << 64) | (low as u128)
+}
+
+/// Computes the full 256-bit result of `a * b + c` for 128-bit operands
+/// using NEON, returned as `(low, high)` like
+/// `karatsuba_multiply_add_128`.
+///
+/// # Safety
+/// Same requirement as [`neon_multiply_add`].
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+pub unsafe fn neon_multiply_add_128(a: u128, b: u128, c: u128) -> (u128, u128) {
+    let a0 = a as u64;
+    let a1 = (a >> 64) as u64;
+    let b0 = b as u64;
+    let b1 = (b >> 64) as u64;
+
+    let p00 = neon_multiply_add(a0, b0, 0);
+    let p01 = neon_multiply_add(a0, b1, 0);
+    let p10 = neon_multiply_add(a1, b0, 0);
+    let p11 = neon_multiply_add(a1, b1, 0);
+
+    let (low, carry1) = p00.overflowing_add(p01 << 64);
+    let (low, carry2) = low.overflowing_add(p10 << 64);
+    let high = p11
+        .wrapping_add(p01 >> 64)
+        .wrapping_add(p10 >> 64)
+        .wrapping_add(carry1 as u128)
+        .wrapping_add(carry2 as u128);
+
+    let (low, c_carry) = low.overflowing_add(c);
+    let high = high.wrapping_add(c_carry as u128);
+
+    (low, high)
+}
+
+#[cfg(all(test, target_arch = "aarch64"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neon_multiply_add_matches_u128_reference_random() {
+        for _ in 0..10_000 {
+            let a: u64 = rand::random();
+            let b: u64 = rand::random();
+            let c: u64 = rand::random();
+
+            let want = (a as u128) * (b as u128) + c as u128;
+            let got = unsafe { neon_multiply_add(a, b, c) };
+            assert_eq!(got, want);
+        }
+    }
+
+    #[test]
+    fn neon_multiply_add_128_matches_widening_random() {
+        use crate::widening::widening_mul_128;
+
+        for _ in 0..10_000 {
+            let a: u128 = rand::random();
+            let b: u128 = rand::random();
+            let c: u128 = rand::random();
+
+            let prod = widening_mul_128(a, b);
+            let (want_low, carry) = prod.low().overflowing_add(c);
+            let want_high = prod.high().wrapping_add(carry as u128);
+
+            let got = unsafe { neon_multiply_add_128(a, b, c) };
+            assert_eq!(got, (want_low, want_high));
+        }
+    }
+}
@@ -0,0 +1,253 @@
+// 256-bit unsigned integer, stored as two 128-bit limbs (low, high), with a
+// full operator set modeled on compiler-builtins' `__udivmodti4` limb
+// arithmetic.
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, BitAnd, BitOr, Shl, Shr, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct U256(pub u128, pub u128);
+
+impl U256 {
+    pub const ZERO: U256 = U256(0, 0);
+    pub const ONE: U256 = U256(1, 0);
+
+    pub fn low(&self) -> u128 {
+        self.0
+    }
+
+    pub fn high(&self) -> u128 {
+        self.1
+    }
+
+    /// Splits the value into four little-endian 64-bit words.
+    pub(crate) fn to_words(self) -> [u64; 4] {
+        [self.0 as u64, (self.0 >> 64) as u64, self.1 as u64, (self.1 >> 64) as u64]
+    }
+
+    /// Reassembles a value from four little-endian 64-bit words.
+    pub(crate) fn from_words(words: [u64; 4]) -> Self {
+        let low = words[0] as u128 | (words[1] as u128) << 64;
+        let high = words[2] as u128 | (words[3] as u128) << 64;
+        U256(low, high)
+    }
+
+    pub fn leading_zeros(&self) -> u32 {
+        if self.1 != 0 {
+            self.1.leading_zeros()
+        } else {
+            128 + self.0.leading_zeros()
+        }
+    }
+
+    /// Divides `self` by `divisor`, returning `(quotient, remainder)`.
+    ///
+    /// Mirrors `__udivmodti4`: operands that both fit in the low limb
+    /// delegate straight to `u128` division, and the general case is a
+    /// bit-by-bit shift-subtract division, with the dividend's leading-zero
+    /// count used to skip the leading bits that can't affect the result.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(self, divisor: U256) -> (U256, U256) {
+        assert!(divisor != U256::ZERO, "division by zero");
+
+        if self.1 == 0 && divisor.1 == 0 {
+            return (U256::from(self.0 / divisor.0), U256::from(self.0 % divisor.0));
+        }
+
+        if self < divisor {
+            return (U256::ZERO, self);
+        }
+
+        // Only the dividend's significant bits need to be brought down;
+        // its leading zeros can never affect the quotient or remainder.
+        let bits = 256 - self.leading_zeros();
+
+        let mut remainder = U256::ZERO;
+        let mut quotient = U256::ZERO;
+        for i in (0..bits).rev() {
+            remainder = (remainder << 1) | ((self >> i) & U256::ONE);
+            if remainder >= divisor {
+                remainder = remainder - divisor;
+                quotient = quotient | (U256::ONE << i);
+            }
+        }
+
+        (quotient, remainder)
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        U256(value, 0)
+    }
+}
+
+impl Add for U256 {
+    type Output = U256;
+
+    fn add(self, rhs: U256) -> U256 {
+        let (low, carry) = self.0.overflowing_add(rhs.0);
+        let high = self.1.wrapping_add(rhs.1).wrapping_add(carry as u128);
+        U256(low, high)
+    }
+}
+
+impl Sub for U256 {
+    type Output = U256;
+
+    fn sub(self, rhs: U256) -> U256 {
+        let (low, borrow) = self.0.overflowing_sub(rhs.0);
+        let high = self.1.wrapping_sub(rhs.1).wrapping_sub(borrow as u128);
+        U256(low, high)
+    }
+}
+
+impl BitAnd for U256 {
+    type Output = U256;
+
+    fn bitand(self, rhs: U256) -> U256 {
+        U256(self.0 & rhs.0, self.1 & rhs.1)
+    }
+}
+
+impl BitOr for U256 {
+    type Output = U256;
+
+    fn bitor(self, rhs: U256) -> U256 {
+        U256(self.0 | rhs.0, self.1 | rhs.1)
+    }
+}
+
+impl Shl<u32> for U256 {
+    type Output = U256;
+
+    fn shl(self, rhs: u32) -> U256 {
+        if rhs == 0 {
+            self
+        } else if rhs >= 256 {
+            U256::ZERO
+        } else if rhs >= 128 {
+            U256(0, self.0 << (rhs - 128))
+        } else {
+            U256(self.0 << rhs, (self.1 << rhs) | (self.0 >> (128 - rhs)))
+        }
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = U256;
+
+    fn shr(self, rhs: u32) -> U256 {
+        if rhs == 0 {
+            self
+        } else if rhs >= 256 {
+            U256::ZERO
+        } else if rhs >= 128 {
+            U256(self.1 >> (rhs - 128), 0)
+        } else {
+            U256((self.0 >> rhs) | (self.1 << (128 - rhs)), self.1 >> rhs)
+        }
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1).then(self.0.cmp(&other.0))
+    }
+}
+
+impl fmt::LowerHex for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.1 == 0 {
+            write!(f, "{:x}", self.0)
+        } else {
+            write!(f, "{:x}{:032x}", self.1, self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_sub_match_u128_when_narrow() {
+        for _ in 0..10_000 {
+            let a: u128 = rand::random::<u64>() as u128;
+            let b: u128 = rand::random::<u64>() as u128;
+            assert_eq!(U256::from(a) + U256::from(b), U256::from(a + b));
+            let (hi, lo) = if a >= b { (a, b) } else { (b, a) };
+            assert_eq!(U256::from(hi) - U256::from(lo), U256::from(hi - lo));
+        }
+    }
+
+    #[test]
+    fn shifts_match_u128_when_narrow() {
+        for shift in 0..64u32 {
+            let v: u128 = 0x1234_5678_9abc_def0;
+            assert_eq!(U256::from(v) << shift, U256::from(v << shift));
+            assert_eq!(U256::from(v) >> shift, U256::from(v >> shift));
+        }
+    }
+
+    #[test]
+    fn shift_across_limb_boundary() {
+        let v = U256(u128::MAX, 0);
+        assert_eq!(v << 128, U256(0, u128::MAX));
+        assert_eq!((v << 128) >> 128, v);
+    }
+
+    #[test]
+    fn div_rem_matches_u128_when_narrow() {
+        for _ in 0..10_000 {
+            let a: u128 = rand::random();
+            let b: u128 = rand::random::<u64>() as u128 + 1; // avoid div-by-zero
+            let (q, r) = U256::from(a).div_rem(U256::from(b));
+            assert_eq!(q, U256::from(a / b));
+            assert_eq!(r, U256::from(a % b));
+        }
+    }
+
+    #[test]
+    fn div_rem_wide_dividend() {
+        // Dividend uses the high limb; divisor does not.
+        let a = U256(0, 1); // 2^128
+        let b = U256::from(3u128);
+        let (q, r) = a.div_rem(b);
+        assert!(r < b);
+        assert_eq!(crate::widening::mul_add_256(q, b, r), a);
+    }
+
+    #[test]
+    fn div_rem_wide_divisor_random() {
+        for _ in 0..1_000 {
+            let a = U256(rand::random(), rand::random::<u64>() as u128);
+            let divisor_low: u128 = rand::random();
+            let b = U256(divisor_low | 1, rand::random::<u64>() as u128); // never zero
+            let (q, r) = a.div_rem(b);
+            assert!(r < b);
+            assert_eq!(crate::widening::mul_add_256(q, b, r), a);
+        }
+    }
+
+    #[test]
+    fn ord_compares_high_limb_first() {
+        let small_high = U256(u128::MAX, 0);
+        let large_high = U256(0, 1);
+        assert!(small_high < large_high);
+    }
+
+    #[test]
+    fn lower_hex_matches_u128_when_narrow() {
+        let v: u128 = 0xdead_beef;
+        assert_eq!(format!("{:x}", U256::from(v)), format!("{:x}", v));
+    }
+}
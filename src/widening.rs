@@ -0,0 +1,124 @@
+// Schoolbook widening multiplication, modeled on compiler-builtins'
+// `__multi3`/`__udivmodti4` limb arithmetic: split each operand into 64-bit
+// limbs, form the four partial products, and accumulate them into the
+// result with explicit carry propagation. This never silently drops a
+// carry, so it doubles as the reference implementation other backends are
+// checked against.
+use crate::u256::U256;
+
+/// Computes the full 256-bit product of two 128-bit values.
+pub fn widening_mul_128(a: u128, b: u128) -> U256 {
+    let a0 = a as u64;
+    let a1 = (a >> 64) as u64;
+    let b0 = b as u64;
+    let b1 = (b >> 64) as u64;
+
+    let p00 = a0 as u128 * b0 as u128;
+    let p01 = a0 as u128 * b1 as u128;
+    let p10 = a1 as u128 * b0 as u128;
+    let p11 = a1 as u128 * b1 as u128;
+
+    let (low, carry1) = p00.overflowing_add(p01 << 64);
+    let (low, carry2) = low.overflowing_add(p10 << 64);
+
+    let high = p11
+        .wrapping_add(p01 >> 64)
+        .wrapping_add(p10 >> 64)
+        .wrapping_add(carry1 as u128)
+        .wrapping_add(carry2 as u128);
+
+    U256(low, high)
+}
+
+/// Computes `(a * b + c) mod 2^256` for 256-bit operands.
+///
+/// The `a*b` product only needs to be correct modulo 2^256, so the cross
+/// terms (`a.low * b.high` and `a.high * b.low`) only contribute their low
+/// 128 bits into the high limb; `widening_mul_128` supplies the full,
+/// carry-correct low-limb product.
+pub fn mul_add_256(a: U256, b: U256, c: U256) -> U256 {
+    let ll = widening_mul_128(a.0, b.0);
+    let cross = a.0.wrapping_mul(b.1).wrapping_add(a.1.wrapping_mul(b.0));
+    let high = ll.1.wrapping_add(cross);
+
+    let (low, carry) = ll.0.overflowing_add(c.0);
+    let high = high.wrapping_add(c.1).wrapping_add(carry as u128);
+
+    U256(low, high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widening_mul_matches_u128_when_narrow() {
+        // Values that fit in 64 bits can never overflow a 128-bit product,
+        // so the reference is exact u128 arithmetic.
+        let cases = [
+            (0u128, 0u128),
+            (1, 1),
+            (u64::MAX as u128, u64::MAX as u128),
+            (0x1234_5678_9abc_def0, 0x0fed_cba9_8765_4321),
+        ];
+        for (a, b) in cases {
+            let got = widening_mul_128(a, b);
+            let want = a * b;
+            assert_eq!(got, U256(want, 0));
+        }
+    }
+
+    #[test]
+    fn widening_mul_matches_u128_with_high_bits_random() {
+        for _ in 0..10_000 {
+            let a: u128 = rand::random();
+            let b: u128 = rand::random();
+            let got = widening_mul_128(a, b);
+
+            // Reconstruct the reference 256-bit product from four 64-bit
+            // limb multiplications done in pure u128 arithmetic.
+            let a0 = a as u64 as u128;
+            let a1 = (a >> 64) as u128;
+            let b0 = b as u64 as u128;
+            let b1 = (b >> 64) as u128;
+
+            let p00 = a0 * b0;
+            let p01 = a0 * b1;
+            let p10 = a1 * b0;
+            let p11 = a1 * b1;
+
+            // `p01` and `p10` are each up to (2^64-1)^2, so a plain `p01 + p10`
+            // can overflow u128 - this has to be a carrying add, not `+`.
+            let (mid, mid_carry) = p01.overflowing_add(p10);
+            let (low, carry) = p00.overflowing_add(mid << 64);
+            let high = p11 + (mid >> 64) + ((mid_carry as u128) << 64) + carry as u128;
+
+            assert_eq!(got, U256(low, high));
+        }
+    }
+
+    #[test]
+    fn widening_mul_matches_u128_at_cross_term_overflow_boundary() {
+        // Hand-computed check at a = b = u128::MAX, where the cross terms
+        // `a0*b1`/`a1*b0` are each (2^64-1)^2 and their sum overflows u128
+        // (the case the random test's oracle needs `mid_carry` for).
+        let got = widening_mul_128(u128::MAX, u128::MAX);
+        // (2^128 - 1)^2 == 2^256 - 2*2^128 + 1
+        assert_eq!(got, U256(1, u128::MAX - 1));
+    }
+
+    #[test]
+    fn mul_add_256_matches_wrapping_u128_math_when_narrow() {
+        for _ in 0..10_000 {
+            let a: u128 = rand::random::<u64>() as u128;
+            let b: u128 = rand::random::<u64>() as u128;
+            let c: u128 = rand::random();
+
+            let got = mul_add_256(U256(a, 0), U256(b, 0), U256(c, 0));
+            let want = a.wrapping_mul(b).overflowing_add(c);
+
+            assert_eq!(got.0, want.0);
+            assert_eq!(got.1, want.1 as u128);
+        }
+    }
+}